@@ -0,0 +1,152 @@
+use std::time::Duration;
+
+use secrecy::{ExposeSecret, Secret};
+
+/// Sends transactional email (confirmation/broadcast) through an HTTP email
+/// API. Split out as a trait so handlers can depend on `dyn EmailTransport`
+/// and tests can inject a mock instead of talking to a real provider.
+#[async_trait::async_trait]
+pub trait EmailTransport: Send + Sync {
+    async fn send_email(
+        &self,
+        recipient: &str,
+        subject: &str,
+        html_body: &str,
+        text_body: &str,
+    ) -> Result<(), reqwest::Error>;
+}
+
+pub struct EmailClient {
+    http_client: reqwest::Client,
+    base_url: String,
+    sender: String,
+    authorization_token: Secret<String>,
+}
+
+impl EmailClient {
+    pub fn new(
+        base_url: String,
+        sender: String,
+        authorization_token: Secret<String>,
+        timeout: Duration,
+    ) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("Failed to build the email client's HTTP client");
+        Self {
+            http_client,
+            base_url,
+            sender,
+            authorization_token,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailTransport for EmailClient {
+    async fn send_email(
+        &self,
+        recipient: &str,
+        subject: &str,
+        html_body: &str,
+        text_body: &str,
+    ) -> Result<(), reqwest::Error> {
+        let url = format!("{}/email", self.base_url);
+        let request_body = SendEmailRequest {
+            from: &self.sender,
+            to: recipient,
+            subject,
+            html_body,
+            text_body,
+        };
+        self.http_client
+            .post(&url)
+            .header(
+                "X-Auth-Token",
+                self.authorization_token.expose_secret().as_str(),
+            )
+            .json(&request_body)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[derive(serde::Serialize)]
+struct SendEmailRequest<'a> {
+    from: &'a str,
+    to: &'a str,
+    subject: &'a str,
+    html_body: &'a str,
+    text_body: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use secrecy::Secret;
+    use wiremock::matchers::{header, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn email_client(base_url: String) -> EmailClient {
+        EmailClient::new(
+            base_url,
+            "sender@zero2prod.io".into(),
+            Secret::new("test-token".into()),
+            Duration::from_millis(200),
+        )
+    }
+
+    #[tokio::test]
+    async fn send_email_posts_to_the_configured_base_url_with_the_auth_header() {
+        let mock_server = MockServer::start().await;
+        let client = email_client(mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/email"))
+            .and(header("X-Auth-Token", "test-token"))
+            .and(header_exists("Content-Type"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let outcome = client
+            .send_email(
+                "recipient@example.com",
+                "subject",
+                "<p>html body</p>",
+                "text body",
+            )
+            .await;
+
+        assert!(outcome.is_ok());
+    }
+
+    #[tokio::test]
+    async fn send_email_fails_when_the_server_returns_a_500() {
+        let mock_server = MockServer::start().await;
+        let client = email_client(mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/email"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let outcome = client
+            .send_email(
+                "recipient@example.com",
+                "subject",
+                "<p>html body</p>",
+                "text body",
+            )
+            .await;
+
+        assert!(outcome.is_err());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod configuration;
+pub mod email_client;
+pub mod startup;
+pub mod telemetry;
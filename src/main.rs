@@ -1,9 +1,13 @@
-use std::net::TcpListener;
-
-use zero2prod::startup::run;
+use zero2prod::configuration::get_configuration;
+use zero2prod::startup::Application;
+use zero2prod::telemetry::{get_subscriber, init_subscriber};
 
 #[tokio::main]
 async fn main() -> Result<(), std::io::Error> {
-    let listener = TcpListener::bind("127.0.0.1:800").expect("Failed to bind to port");
-    run(listener)?.await
+    let subscriber = get_subscriber("zero2prod".into(), "info".into(), std::io::stdout);
+    init_subscriber(subscriber);
+
+    let configuration = get_configuration().expect("Failed to read configuration");
+    let application = Application::build(configuration)?;
+    application.run_until_stopped().await
 }
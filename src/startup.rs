@@ -0,0 +1,117 @@
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::dev::Server;
+use actix_web::{web, App, HttpServer};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+use tracing_actix_web::TracingLogger;
+
+use crate::configuration::{DatabaseSettings, Settings};
+use crate::email_client::{EmailClient, EmailTransport};
+
+pub fn run(
+    listener: TcpListener,
+    db_pool: PgPool,
+    email_client: Arc<dyn EmailTransport>,
+    shutdown_grace_period: Duration,
+) -> Result<Server, std::io::Error> {
+    let db_pool = web::Data::new(db_pool);
+    let email_client = web::Data::from(email_client);
+    let server = HttpServer::new(move || {
+        App::new()
+            .wrap(TracingLogger::default())
+            .app_data(db_pool.clone())
+            .app_data(email_client.clone())
+    })
+    .listen(listener)?
+    .shutdown_timeout(shutdown_grace_period.as_secs())
+    .run();
+    Ok(server)
+}
+
+/// A running instance of the application, bound to the configured host and
+/// port (the OS assigns a free port when the configured port is `0`).
+///
+/// Keeping track of the actual port (rather than the one requested in
+/// configuration) lets callers - in particular integration tests that bind
+/// to port `0` - discover where the server actually ended up listening.
+pub struct Application {
+    port: u16,
+    server: Server,
+}
+
+impl Application {
+    pub fn build(configuration: Settings) -> Result<Self, std::io::Error> {
+        let connection_pool = get_connection_pool(&configuration.database);
+        let email_client: Arc<dyn EmailTransport> = Arc::new(EmailClient::new(
+            configuration.email_client.base_url.clone(),
+            configuration.email_client.sender_email.clone(),
+            configuration.email_client.authorization_token.clone(),
+            configuration.email_client.timeout(),
+        ));
+
+        let address = format!(
+            "{}:{}",
+            configuration.application.host, configuration.application.port
+        );
+        let listener = TcpListener::bind(address)?;
+        let port = listener.local_addr().unwrap().port();
+        let shutdown_grace_period =
+            Duration::from_secs(configuration.application.shutdown_grace_period_seconds);
+        let server = run(listener, connection_pool, email_client, shutdown_grace_period)?;
+
+        Ok(Self { port, server })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Runs the server until a shutdown signal (SIGINT or, on Unix, SIGTERM)
+    /// arrives. New connections stop being accepted immediately; in-flight
+    /// requests get up to the configured grace period to finish.
+    pub async fn run_until_stopped(self) -> Result<(), std::io::Error> {
+        let handle = self.server.handle();
+        let mut server_task = tokio::spawn(self.server);
+
+        tokio::select! {
+            result = &mut server_task => {
+                return result.expect("Server task panicked");
+            }
+            _ = tokio::signal::ctrl_c() => {
+                tracing::info!("Received Ctrl+C, starting graceful shutdown");
+            }
+            _ = wait_for_sigterm() => {
+                tracing::info!("Received SIGTERM, starting graceful shutdown");
+            }
+        }
+
+        handle.stop(true).await;
+        server_task.await.expect("Server task panicked")
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_sigterm() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    signal(SignalKind::terminate())
+        .expect("Failed to install SIGTERM handler")
+        .recv()
+        .await;
+}
+
+#[cfg(not(unix))]
+async fn wait_for_sigterm() {
+    std::future::pending().await
+}
+
+/// Builds a lazily-connecting pool so the server can start (and tests can
+/// bind a port) even before the database is reachable.
+pub fn get_connection_pool(configuration: &DatabaseSettings) -> PgPool {
+    PgPoolOptions::new()
+        .acquire_timeout(Duration::from_secs(2))
+        .connect_lazy_with(configuration.with_db())
+}
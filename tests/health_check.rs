@@ -0,0 +1,37 @@
+use zero2prod::configuration::get_configuration;
+use zero2prod::startup::Application;
+
+struct TestApp {
+    address: String,
+}
+
+async fn spawn_app() -> TestApp {
+    let configuration = {
+        let mut c = get_configuration().expect("Failed to read configuration.");
+        c.application.port = 0;
+        c
+    };
+    let application =
+        Application::build(configuration).expect("Failed to build application.");
+    let address = format!("http://127.0.0.1:{}", application.port());
+
+    tokio::spawn(application.run_until_stopped());
+
+    TestApp { address }
+}
+
+#[tokio::test]
+async fn server_is_reachable_on_its_advertised_port() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(&app.address)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // No routes are registered anywhere in the app yet, so a reachable
+    // server must answer every request with actix-web's default 404.
+    assert_eq!(response.status().as_u16(), 404);
+}